@@ -0,0 +1,90 @@
+use std::path::{Path, PathBuf};
+
+use crate::auth::AuthMode;
+
+const CONFIG_FILE_NAME: &str = "config.toml";
+const APP_NAME: &str = "github-codemunch-mcp";
+
+const DEFAULT_GITHUB_API_BASE: &str = "https://api.github.com";
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_RATE_LIMIT_BUFFER: u32 = 5;
+
+/// Deployment-tunable settings, loaded from `config.toml`.
+#[derive(serde::Deserialize)]
+pub(crate) struct Config {
+    #[serde(default = "default_max_retries")]
+    pub(crate) max_retries: u32,
+    #[serde(default = "default_github_api_base")]
+    pub(crate) github_api_base: String,
+    #[serde(default)]
+    pub(crate) auth: AuthMode,
+    #[serde(default = "default_request_timeout_secs")]
+    pub(crate) request_timeout_secs: u64,
+    #[serde(default = "default_rate_limit_buffer")]
+    pub(crate) rate_limit_buffer: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            max_retries: default_max_retries(),
+            github_api_base: default_github_api_base(),
+            auth: AuthMode::PersonalAccessToken,
+            request_timeout_secs: default_request_timeout_secs(),
+            rate_limit_buffer: default_rate_limit_buffer(),
+        }
+    }
+}
+
+fn default_max_retries() -> u32 {
+    DEFAULT_MAX_RETRIES
+}
+
+fn default_github_api_base() -> String {
+    DEFAULT_GITHUB_API_BASE.to_string()
+}
+
+fn default_request_timeout_secs() -> u64 {
+    DEFAULT_REQUEST_TIMEOUT_SECS
+}
+
+fn default_rate_limit_buffer() -> u32 {
+    DEFAULT_RATE_LIMIT_BUFFER
+}
+
+impl Config {
+    /// Load configuration, checking the working directory then the OS config directory,
+    /// and falling back to defaults when no file is present.
+    ///
+    /// Exits the process with a diagnostic if a file is found but fails to parse, rather
+    /// than panicking mid-run.
+    pub(crate) fn load() -> Config {
+        let candidates = [
+            PathBuf::from(CONFIG_FILE_NAME),
+            dirs::config_dir()
+                .map(|dir| dir.join(APP_NAME).join(CONFIG_FILE_NAME))
+                .unwrap_or_default(),
+        ];
+
+        for path in candidates {
+            if path.as_os_str().is_empty() || !path.is_file() {
+                continue;
+            }
+            return Self::load_from(&path);
+        }
+
+        Config::default()
+    }
+
+    fn load_from(path: &Path) -> Config {
+        let contents = std::fs::read_to_string(path).unwrap_or_else(|e| {
+            eprintln!("error: failed to read {}: {e}", path.display());
+            std::process::exit(1);
+        });
+        toml::from_str(&contents).unwrap_or_else(|e| {
+            eprintln!("error: malformed config at {}: {e}", path.display());
+            std::process::exit(1);
+        })
+    }
+}
@@ -0,0 +1,245 @@
+const USER_AGENT: &str = "github-codemunch-mcp";
+
+/// A GitHub user's public profile.
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct User {
+    pub(crate) id: u32,
+    pub(crate) login: String,
+    pub(crate) name: String,
+    pub(crate) avatar_url: String,
+    pub(crate) html_url: String,
+    pub(crate) public_repos: u32,
+    pub(crate) public_gists: u32,
+}
+
+impl User {
+    /// Create a new user
+    fn new(
+        id: u32,
+        login: String,
+        name: String,
+        avatar_url: String,
+        html_url: String,
+        public_repos: u32,
+        public_gists: u32,
+    ) -> Self {
+        User {
+            id,
+            login,
+            name,
+            avatar_url,
+            html_url,
+            public_repos,
+            public_gists,
+        }
+    }
+}
+
+/// A GitHub user as returned by `GET /user`.
+#[derive(serde::Deserialize)]
+struct GithubUser {
+    id: u32,
+    login: String,
+    name: Option<String>,
+    avatar_url: String,
+    html_url: String,
+    public_repos: u32,
+    public_gists: u32,
+}
+
+/// One of a user's repositories, as surfaced to MCP tooling.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct Repository {
+    pub(crate) name: String,
+    #[serde(rename = "stargazers_count")]
+    pub(crate) star_count: u32,
+    #[serde(rename = "forks_count")]
+    pub(crate) fork_count: u32,
+    pub(crate) description: Option<String>,
+    pub(crate) language: Option<String>,
+}
+
+impl From<GithubUser> for User {
+    fn from(gh: GithubUser) -> Self {
+        let name = gh.name.unwrap_or_else(|| gh.login.clone());
+        User::new(
+            gh.id,
+            gh.login,
+            name,
+            gh.avatar_url,
+            gh.html_url,
+            gh.public_repos,
+            gh.public_gists,
+        )
+    }
+}
+
+/// The shape of a `GET /search/repositories` response.
+#[derive(serde::Deserialize)]
+struct RepositorySearchResponse {
+    items: Vec<Repository>,
+}
+
+impl User {
+    /// Fetch this user's newest and most-starred repositories. The GitHub REST API's
+    /// `GET /users/{username}/repos` endpoint only supports sorting by `created`,
+    /// `updated`, `pushed`, or `full_name` — sorting by stars requires the Search API.
+    pub(crate) fn fetch_repositories(
+        &self,
+        client: &crate::client::GithubClient,
+    ) -> Result<Vec<Repository>, crate::client::ClientError> {
+        let newest: Vec<Repository> = client
+            .request(&format!(
+                "/users/{}/repos?sort=created&direction=desc",
+                self.login
+            ))?
+            .json()
+            .map_err(crate::client::ClientError::Network)?;
+
+        let most_starred: RepositorySearchResponse = client
+            .request(&format!(
+                "/search/repositories?q=user:{}&sort=stars&order=desc",
+                self.login
+            ))?
+            .json()
+            .map_err(crate::client::ClientError::Network)?;
+
+        let mut seen = std::collections::HashSet::new();
+        let repos = newest
+            .into_iter()
+            .chain(most_starred.items)
+            .filter(|repo| seen.insert(repo.name.clone()))
+            .collect();
+        Ok(repos)
+    }
+}
+
+/// Errors that can occur while authenticating a token against the GitHub API.
+#[derive(Debug)]
+pub(crate) enum AuthError {
+    /// The request could not be completed, or its response body could not be decoded.
+    Network(reqwest::Error),
+    /// GitHub rejected the token (401/403).
+    InvalidToken,
+    /// GitHub is still rate-limiting us after retrying.
+    RateLimited,
+    /// GitHub returned a status that's neither success nor an auth rejection (e.g. a
+    /// 5xx outage), so the token's validity is unknown.
+    UnexpectedStatus(reqwest::StatusCode),
+}
+
+impl std::fmt::Display for AuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthError::Network(e) => write!(f, "network error: {e}"),
+            AuthError::InvalidToken => write!(f, "invalid token"),
+            AuthError::RateLimited => write!(f, "rate limited by GitHub"),
+            AuthError::UnexpectedStatus(s) => write!(f, "unexpected status from GitHub: {s}"),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+/// Configuration for verifying bearer tokens issued by an OAuth2 provider.
+#[derive(serde::Deserialize)]
+pub(crate) struct OAuth2Config {
+    pub(crate) client_id: String,
+    pub(crate) client_secret: String,
+    pub(crate) audience: String,
+    pub(crate) issuer_url: String,
+}
+
+/// How an incoming token should be verified.
+#[derive(Default, serde::Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub(crate) enum AuthMode {
+    /// A GitHub personal access token, verified against the GitHub API.
+    #[default]
+    PersonalAccessToken,
+    /// An OAuth2 bearer token, verified against a configured provider.
+    #[serde(rename = "oauth2")]
+    OAuth2(OAuth2Config),
+}
+
+/// The subject claims a provider's introspection endpoint returns for an active token.
+#[derive(serde::Deserialize)]
+struct IntrospectionResponse {
+    active: bool,
+    sub: Option<String>,
+}
+
+/// Authenticate a token using the given mode and return the authenticated user. All
+/// calls route through the retry/backoff/rate-limit machinery in [`crate::client`].
+pub(crate) fn authenticate(
+    token: &str,
+    mode: &AuthMode,
+    config: &crate::app_conf::Config,
+) -> Result<User, AuthError> {
+    match mode {
+        AuthMode::PersonalAccessToken => authenticate_pat(token, config),
+        AuthMode::OAuth2(oauth_config) => authenticate_oauth2(token, oauth_config, config),
+    }
+}
+
+impl From<crate::client::ClientError> for AuthError {
+    fn from(e: crate::client::ClientError) -> Self {
+        match e {
+            crate::client::ClientError::Network(e) => AuthError::Network(e),
+            crate::client::ClientError::Status(
+                reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN,
+            ) => AuthError::InvalidToken,
+            crate::client::ClientError::Status(status) => AuthError::UnexpectedStatus(status),
+            crate::client::ClientError::RateLimited => AuthError::RateLimited,
+        }
+    }
+}
+
+/// Authenticate a GitHub personal access token against the GitHub API.
+fn authenticate_pat(token: &str, config: &crate::app_conf::Config) -> Result<User, AuthError> {
+    let client = crate::client::GithubClient::new(config, token);
+    let github_user: GithubUser = client.request("/user")?.json().map_err(AuthError::Network)?;
+    Ok(github_user.into())
+}
+
+/// Verify an OAuth2 bearer token against the provider's introspection endpoint and
+/// extract the subject claim to populate the returned `User`. Routes through the same
+/// shared retry/backoff/rate-limit helper as [`crate::client::GithubClient`], since the
+/// introspection endpoint lives outside the GitHub API and can't reuse that client
+/// directly.
+fn authenticate_oauth2(
+    token: &str,
+    oauth_config: &OAuth2Config,
+    config: &crate::app_conf::Config,
+) -> Result<User, AuthError> {
+    let http = reqwest::blocking::Client::new();
+    let response = crate::client::with_retries(config.max_retries, config.rate_limit_buffer, || {
+        http.post(format!("{}/oauth2/introspect", oauth_config.issuer_url))
+            .header("User-Agent", USER_AGENT)
+            .basic_auth(&oauth_config.client_id, Some(&oauth_config.client_secret))
+            .form(&[
+                ("token", token),
+                ("token_type_hint", "access_token"),
+                ("audience", &oauth_config.audience),
+            ])
+            .send()
+    })?;
+
+    let introspection: IntrospectionResponse = response.json().map_err(AuthError::Network)?;
+    if !introspection.active {
+        return Err(AuthError::InvalidToken);
+    }
+    let subject = introspection.sub.ok_or(AuthError::InvalidToken)?;
+    // The introspection endpoint only yields a subject claim, not a full GitHub
+    // profile, so the remaining fields are left blank (zero id and counts, empty URLs);
+    // the subject is not resolved against the GitHub API here.
+    Ok(User::new(
+        0,
+        subject.clone(),
+        subject,
+        String::new(),
+        String::new(),
+        0,
+        0,
+    ))
+}
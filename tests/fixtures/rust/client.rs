@@ -0,0 +1,282 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::app_conf::Config;
+
+const BASE_BACKOFF_MS: u64 = 250;
+
+/// Errors that can occur while issuing a request through [`with_retries`].
+#[derive(Debug)]
+pub(crate) enum ClientError {
+    /// The request failed on every attempt, including retries, or the response body
+    /// could not be decoded.
+    Network(reqwest::Error),
+    /// GitHub returned a non-transient error status, or a transient one on every attempt.
+    Status(reqwest::StatusCode),
+    /// GitHub is still rate-limiting us after `max_retries` attempts to wait it out.
+    RateLimited,
+}
+
+impl std::fmt::Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Network(e) => write!(f, "network error: {e}"),
+            ClientError::Status(s) => write!(f, "GitHub returned {s}"),
+            ClientError::RateLimited => write!(f, "still rate limited by GitHub after retrying"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// Send a request built by `send`, retrying transient failures up to `max_retries`
+/// times with exponential backoff. A rate-limited response sleeps until the reported
+/// reset instead of backing off; each such wait counts as one of the `max_retries`
+/// attempts. Shared by [`GithubClient::request`] and OAuth2 introspection,
+/// since both need the same retry/backoff/rate-limit behavior against different hosts.
+pub(crate) fn with_retries(
+    max_retries: u32,
+    rate_limit_buffer: u32,
+    mut send: impl FnMut() -> Result<reqwest::blocking::Response, reqwest::Error>,
+) -> Result<reqwest::blocking::Response, ClientError> {
+    let mut attempt = 0;
+    loop {
+        let response = match send() {
+            Ok(response) => response,
+            Err(e) => {
+                if attempt >= max_retries {
+                    return Err(ClientError::Network(e));
+                }
+                backoff_sleep(attempt);
+                attempt += 1;
+                continue;
+            }
+        };
+
+        if is_rate_limited(&response, rate_limit_buffer) {
+            if attempt >= max_retries {
+                return Err(ClientError::RateLimited);
+            }
+            sleep_until_rate_limit_reset(&response);
+            attempt += 1;
+            continue;
+        }
+
+        let status = response.status();
+        if is_transient(status) {
+            if attempt >= max_retries {
+                return Err(ClientError::Status(status));
+            }
+            backoff_sleep(attempt);
+            attempt += 1;
+            continue;
+        }
+
+        if !status.is_success() {
+            return Err(ClientError::Status(status));
+        }
+
+        return Ok(response);
+    }
+}
+
+/// A GitHub API client that retries transient failures with exponential backoff and
+/// sleeps until the rate limit resets instead of backing off when rate limited.
+pub(crate) struct GithubClient {
+    http: reqwest::blocking::Client,
+    base_url: String,
+    max_retries: u32,
+    rate_limit_buffer: u32,
+    token: String,
+}
+
+impl GithubClient {
+    /// Build a client from the loaded `Config`, authenticated as `token`.
+    pub(crate) fn new(config: &Config, token: &str) -> GithubClient {
+        let http = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(config.request_timeout_secs))
+            .build()
+            .expect("failed to build HTTP client");
+        GithubClient {
+            http,
+            base_url: config.github_api_base.clone(),
+            max_retries: config.max_retries,
+            rate_limit_buffer: config.rate_limit_buffer,
+            token: token.to_string(),
+        }
+    }
+
+    /// Issue a `GET` request to `path` (relative to the configured API base), retrying
+    /// transient failures up to `max_retries` times with exponential backoff, and
+    /// waiting out rate limits rather than retrying through them.
+    pub(crate) fn request(&self, path: &str) -> Result<reqwest::blocking::Response, ClientError> {
+        with_retries(self.max_retries, self.rate_limit_buffer, || {
+            self.http
+                .get(format!("{}{path}", self.base_url))
+                .header("Authorization", format!("Bearer {}", self.token))
+                .header("User-Agent", "github-codemunch-mcp")
+                .send()
+        })
+    }
+}
+
+pub(crate) fn is_transient(status: reqwest::StatusCode) -> bool {
+    matches!(
+        status,
+        reqwest::StatusCode::TOO_MANY_REQUESTS
+            | reqwest::StatusCode::BAD_GATEWAY
+            | reqwest::StatusCode::SERVICE_UNAVAILABLE
+    )
+}
+
+/// Whether `response` is a 429, or a 403 with `X-RateLimit-Remaining` at or below
+/// `rate_limit_buffer`, so the caller should sleep instead of retrying immediately.
+/// Other statuses are never treated as rate limited, whatever the header says.
+pub(crate) fn is_rate_limited(
+    response: &reqwest::blocking::Response,
+    rate_limit_buffer: u32,
+) -> bool {
+    let remaining = response
+        .headers()
+        .get("X-RateLimit-Remaining")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u32>().ok());
+    is_rate_limited_status(response.status(), remaining, rate_limit_buffer)
+}
+
+/// The pure classification behind [`is_rate_limited`], taking the status and parsed
+/// `X-RateLimit-Remaining` header directly so it can be unit tested without a live
+/// `Response`.
+fn is_rate_limited_status(
+    status: reqwest::StatusCode,
+    remaining: Option<u32>,
+    rate_limit_buffer: u32,
+) -> bool {
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return true;
+    }
+    if status != reqwest::StatusCode::FORBIDDEN {
+        return false;
+    }
+    remaining.is_some_and(|remaining| remaining <= rate_limit_buffer)
+}
+
+pub(crate) fn sleep_until_rate_limit_reset(response: &reqwest::blocking::Response) {
+    let reset_epoch = response
+        .headers()
+        .get("X-RateLimit-Reset")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok());
+
+    let Some(reset_epoch) = reset_epoch else {
+        backoff_sleep(0);
+        return;
+    };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    std::thread::sleep(Duration::from_secs(wait_secs_until(reset_epoch, now)));
+}
+
+/// The pure epoch math behind [`sleep_until_rate_limit_reset`]: how long to wait, given
+/// the reset epoch GitHub reported and the current epoch.
+fn wait_secs_until(reset_epoch: u64, now: u64) -> u64 {
+    reset_epoch.saturating_sub(now)
+}
+
+/// Exponential backoff with jitter: `BASE_BACKOFF_MS * 2^attempt` (the exponent is
+/// capped at 16), plus up to `BASE_BACKOFF_MS` of random jitter to avoid
+/// thundering-herd retries.
+pub(crate) fn backoff_sleep(attempt: u32) {
+    let jitter = rand::random::<u64>() % BASE_BACKOFF_MS;
+    std::thread::sleep(Duration::from_millis(backoff_base_ms(attempt) + jitter));
+}
+
+/// The deterministic part of [`backoff_sleep`]'s delay, excluding jitter.
+fn backoff_base_ms(attempt: u32) -> u64 {
+    BASE_BACKOFF_MS.saturating_mul(1u64 << attempt.min(16))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transient_statuses_are_retried() {
+        assert!(is_transient(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_transient(reqwest::StatusCode::BAD_GATEWAY));
+        assert!(is_transient(reqwest::StatusCode::SERVICE_UNAVAILABLE));
+    }
+
+    #[test]
+    fn non_transient_statuses_are_not_retried() {
+        assert!(!is_transient(reqwest::StatusCode::OK));
+        assert!(!is_transient(reqwest::StatusCode::NOT_FOUND));
+        assert!(!is_transient(reqwest::StatusCode::UNAUTHORIZED));
+    }
+
+    #[test]
+    fn too_many_requests_is_always_rate_limited() {
+        assert!(is_rate_limited_status(
+            reqwest::StatusCode::TOO_MANY_REQUESTS,
+            None,
+            0,
+        ));
+    }
+
+    #[test]
+    fn forbidden_is_rate_limited_within_the_buffer() {
+        assert!(is_rate_limited_status(
+            reqwest::StatusCode::FORBIDDEN,
+            Some(3),
+            5,
+        ));
+        assert!(is_rate_limited_status(
+            reqwest::StatusCode::FORBIDDEN,
+            Some(5),
+            5,
+        ));
+    }
+
+    #[test]
+    fn forbidden_is_not_rate_limited_outside_the_buffer() {
+        assert!(!is_rate_limited_status(
+            reqwest::StatusCode::FORBIDDEN,
+            Some(6),
+            5,
+        ));
+        assert!(!is_rate_limited_status(
+            reqwest::StatusCode::FORBIDDEN,
+            None,
+            5,
+        ));
+    }
+
+    #[test]
+    fn non_forbidden_statuses_are_never_rate_limited() {
+        assert!(!is_rate_limited_status(reqwest::StatusCode::OK, Some(0), 5));
+    }
+
+    #[test]
+    fn wait_secs_until_reset_in_the_future() {
+        assert_eq!(wait_secs_until(1_000, 990), 10);
+    }
+
+    #[test]
+    fn wait_secs_until_reset_already_passed() {
+        assert_eq!(wait_secs_until(1_000, 1_010), 0);
+    }
+
+    #[test]
+    fn backoff_base_doubles_per_attempt() {
+        assert_eq!(backoff_base_ms(0), BASE_BACKOFF_MS);
+        assert_eq!(backoff_base_ms(1), BASE_BACKOFF_MS * 2);
+        assert_eq!(backoff_base_ms(2), BASE_BACKOFF_MS * 4);
+    }
+
+    #[test]
+    fn backoff_base_is_capped_to_avoid_overflow() {
+        assert_eq!(backoff_base_ms(16), backoff_base_ms(100));
+    }
+}
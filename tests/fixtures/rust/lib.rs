@@ -0,0 +1,4 @@
+pub(crate) mod app_conf;
+pub(crate) mod auth;
+pub(crate) mod client;
+pub(crate) mod token;
@@ -0,0 +1,84 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::app_conf::Config;
+use crate::auth::{authenticate, AuthError, AuthMode, User};
+
+const APP_NAME: &str = "github-codemunch-mcp";
+
+/// A persisted GitHub auth token, stored in the OS-appropriate config directory.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Token {
+    pub username: String,
+    pub value: String,
+}
+
+impl Token {
+    fn path() -> Option<PathBuf> {
+        Some(dirs::config_dir()?.join(APP_NAME).join("token.json"))
+    }
+
+    /// Load a previously saved token, if one exists.
+    pub fn load() -> Option<Token> {
+        let path = Self::path()?;
+        let contents = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// Persist this token to the platform config directory, creating it if missing.
+    pub fn save(&self) -> std::io::Result<()> {
+        let path = Self::path().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "no config directory")
+        })?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(path, contents)
+    }
+
+    /// Remove any saved token.
+    pub fn clear() -> std::io::Result<()> {
+        if let Some(path) = Self::path() {
+            match fs::remove_file(path) {
+                Ok(()) => Ok(()),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+                Err(e) => Err(e),
+            }
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Prompt the user for their GitHub username and a personal access token, validate it,
+/// and persist it on success. Run this on first startup when no token is saved.
+pub fn first_run_auth(config: &Config) -> Result<Token, AuthError> {
+    print!("GitHub username: ");
+    std::io::stdout().flush().ok();
+    let mut username = String::new();
+    std::io::stdin().read_line(&mut username).ok();
+    let username = username.trim().to_string();
+
+    let value = rpassword::prompt_password("GitHub personal access token: ")
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+
+    let User { .. } = authenticate(&value, &AuthMode::PersonalAccessToken, config)?;
+
+    let token = Token { username, value };
+    if let Err(e) = token.save() {
+        eprintln!("warning: failed to persist token: {e}");
+    }
+    Ok(token)
+}
+
+/// Load the saved token, or fall back to the interactive first-run flow.
+pub fn load_or_authenticate(config: &Config) -> Result<Token, AuthError> {
+    match Token::load() {
+        Some(token) => Ok(token),
+        None => first_run_auth(config),
+    }
+}